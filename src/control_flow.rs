@@ -0,0 +1,52 @@
+//! Tap operations for `core::ops::ControlFlow`, requires the feature `control_flow`.
+use core::ops::ControlFlow;
+
+/// Tap operations for `core::ops::ControlFlow`.
+pub trait TapControlFlowOps<B, C> {
+    /// Executes a closure if the value is `ControlFlow::Continue(C)`.
+    fn tap_continue<R, F: FnOnce(&mut C) -> R>(self, f: F) -> Self;
+
+    /// Executes a closure if the value is `ControlFlow::Break(B)`.
+    fn tap_break<R, F: FnOnce(&mut B) -> R>(self, f: F) -> Self;
+}
+
+impl<B, C> TapControlFlowOps<B, C> for ControlFlow<B, C> {
+    fn tap_continue<R, F: FnOnce(&mut C) -> R>(mut self, f: F) -> Self {
+        if let ControlFlow::Continue(ref mut val) = self {
+            let _ = f(val);
+        }
+        self
+    }
+
+    fn tap_break<R, F: FnOnce(&mut B) -> R>(mut self, f: F) -> Self {
+        if let ControlFlow::Break(ref mut val) = self {
+            let _ = f(val);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn continue_() {
+        let mut foo = 0;
+        let cf: ControlFlow<u32, u32> = ControlFlow::Continue(4);
+        assert!(matches!(cf.tap_continue(|v| foo += *v), ControlFlow::Continue(4)));
+        assert_eq!(foo, 4);
+    }
+
+    #[test]
+    fn break_() {
+        let mut foo = 0;
+        let cf: ControlFlow<u32, u32> = ControlFlow::Break(4);
+        assert!(matches!(cf.tap_continue(|v| foo += *v), ControlFlow::Break(4)));
+        assert_eq!(foo, 0);
+
+        let cf: ControlFlow<u32, u32> = ControlFlow::Break(4);
+        assert!(matches!(cf.tap_break(|v| foo += *v), ControlFlow::Break(4)));
+        assert_eq!(foo, 4);
+    }
+}
@@ -1,39 +1,105 @@
 extern crate futures;
 
-use self::futures::{Async, Future};
+use self::futures::{Async, Future, Poll};
 
 /// Tap operations for `Future`.
-pub trait TapFutureOps<T, E> {
-    /// Executes a closure if the value is `Async::Ready(T)`.
-    fn tap_ready<R, F: FnOnce(&T) -> R>(self, f: F) -> Self;
+pub trait TapFutureOps<T, E>: Future<Item = T, Error = E> + Sized {
+    /// Returns a future that invokes a closure with `&T` once the inner
+    /// future resolves to `Async::Ready(T)`, then forwards the result.
+    fn tap_ready<R, F: FnOnce(&T) -> R>(self, f: F) -> TapReady<Self, F>;
 
-    // Executes a closure if the value is `Async::NotReady`.
-    fn tap_not_ready<R, F: FnOnce() -> R>(self, f: F) -> Self;
+    /// Returns a future that invokes a closure once the inner future polls
+    /// `Async::NotReady`, then forwards the result.
+    fn tap_not_ready<R, F: FnOnce() -> R>(self, f: F) -> TapNotReady<Self, F>;
 
-    // Executes a closure if the value is `Err(E)`.
-    fn tap_err<R, F: FnOnce(&E) -> R>(self, f: F) -> Self;
+    /// Returns a future that invokes a closure with `&E` once the inner
+    /// future resolves to `Err(E)`, then forwards the result.
+    fn tap_err<R, F: FnOnce(&E) -> R>(self, f: F) -> TapErr<Self, F>;
 }
 
 impl<T, E, FUT: Future<Item = T, Error = E>> TapFutureOps<T, E> for FUT {
-    fn tap_ready<R, F: FnOnce(&T) -> R>(mut self, f: F) -> Self {
-        if let Ok(Async::Ready(ref val)) = self.poll() {
-            let _ = f(val);
+    fn tap_ready<R, F: FnOnce(&T) -> R>(self, f: F) -> TapReady<Self, F> {
+        TapReady { future: self, f: Some(f) }
+    }
+
+    fn tap_not_ready<R, F: FnOnce() -> R>(self, f: F) -> TapNotReady<Self, F> {
+        TapNotReady { future: self, f: Some(f) }
+    }
+
+    fn tap_err<R, F: FnOnce(&E) -> R>(self, f: F) -> TapErr<Self, F> {
+        TapErr { future: self, f: Some(f) }
+    }
+}
+
+/// Future for [`TapFutureOps::tap_ready`].
+pub struct TapReady<Fut, F> {
+    future: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F, R> Future for TapReady<Fut, F>
+    where Fut: Future,
+          F: FnOnce(&Fut::Item) -> R
+{
+    type Item = Fut::Item;
+    type Error = Fut::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let poll = self.future.poll();
+        if let Ok(Async::Ready(ref val)) = poll {
+            if let Some(f) = self.f.take() {
+                let _ = f(val);
+            }
         }
-        self
+        poll
     }
+}
 
-    fn tap_not_ready<R, F: FnOnce() -> R>(mut self, f: F) -> Self {
-        if let Ok(Async::NotReady) = self.poll() {
-            let _ = f();
+/// Future for [`TapFutureOps::tap_not_ready`].
+pub struct TapNotReady<Fut, F> {
+    future: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F, R> Future for TapNotReady<Fut, F>
+    where Fut: Future,
+          F: FnOnce() -> R
+{
+    type Item = Fut::Item;
+    type Error = Fut::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let poll = self.future.poll();
+        if let Ok(Async::NotReady) = poll {
+            if let Some(f) = self.f.take() {
+                let _ = f();
+            }
         }
-        self
+        poll
     }
+}
+
+/// Future for [`TapFutureOps::tap_err`].
+pub struct TapErr<Fut, F> {
+    future: Fut,
+    f: Option<F>,
+}
+
+impl<Fut, F, R> Future for TapErr<Fut, F>
+    where Fut: Future,
+          F: FnOnce(&Fut::Error) -> R
+{
+    type Item = Fut::Item;
+    type Error = Fut::Error;
 
-    fn tap_err<R, F: FnOnce(&E) -> R>(mut self, f: F) -> Self {
-        if let Err(ref val) = self.poll() {
-            let _ = f(val);
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let poll = self.future.poll();
+        if let Err(ref val) = poll {
+            if let Some(f) = self.f.take() {
+                let _ = f(val);
+            }
         }
-        self
+        poll
     }
 }
 
@@ -46,17 +112,21 @@ mod tests {
         let mut foo = 0;
         let future = futures::future::result::<i32, i32>(Ok(5));
 
-        let _ = future.tap_ready(|x| foo += *x);
+        let _ = future.tap_ready(|x| foo += *x).poll();
         assert_eq!(foo, 5);
     }
 
     #[test]
     fn not_ready() {
-        let mut foo = 0;
-        let future = futures::future::empty::<i32, i32>();
+        let foo = std::cell::Cell::new(0);
+        let mut future = futures::future::empty::<i32, i32>().tap_not_ready(|| foo.set(foo.get() + 5));
 
-        assert_matches!(future.tap_not_ready(|| foo += 5).poll(), Ok(Async::NotReady));
-        assert_eq!(foo, 5);
+        assert_matches!(future.poll(), Ok(Async::NotReady));
+        assert_eq!(foo.get(), 5);
+
+        // Polling again must not invoke the closure a second time.
+        assert_matches!(future.poll(), Ok(Async::NotReady));
+        assert_eq!(foo.get(), 5);
     }
 
     #[test]
@@ -64,7 +134,7 @@ mod tests {
         let mut foo = 0;
         let future = futures::future::result::<i32, i32>(Err(5));
 
-        let _ = future.tap_err(|x| foo += *x);
+        let _ = future.tap_err(|x| foo += *x).poll();
         assert_eq!(foo, 5);
     }
 }
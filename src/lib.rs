@@ -54,7 +54,7 @@
 //! ```
 
 #[cfg(feature = "future")]
-pub use self::future::TapFutureOps;
+pub use self::future::{TapFutureOps, TapReady, TapNotReady, TapErr};
 
 #[cfg(feature = "future")]
 mod future;
@@ -65,6 +65,12 @@ pub use self::nom::TapNomOps;
 #[cfg(feature = "nom3")]
 mod nom;
 
+#[cfg(feature = "control_flow")]
+pub use self::control_flow::TapControlFlowOps;
+
+#[cfg(feature = "control_flow")]
+mod control_flow;
+
 #[cfg(test)]
 #[cfg_attr(test, macro_use)]
 extern crate matches;
@@ -153,6 +159,31 @@ pub trait TapResultOps<T, E> {
     /// ```
     fn tap_ok<R, F: FnOnce(&mut T) -> R>(self, f: F) -> Self;
 
+    /// Executes a closure with a read-only reference if the value is `Result::Ok(T)`.
+    ///
+    /// Unlike [`tap_ok`](TapResultOps::tap_ok), the closure only receives `&T`, so it cannot
+    /// accidentally mutate the contained value; useful when the side effect only needs to
+    /// read, clone or send a copy of the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let mut foo = 0;
+    /// let res: Result<u32, u32> = Ok(4);
+    /// assert_eq!(res.tap_ref_ok(|v| foo += v), Ok(4));
+    /// assert_eq!(foo, 4);
+    /// ```
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let mut foo = 0;
+    /// let res: Result<u32, u32> = Err(4);
+    /// assert_eq!(res.tap_ref_ok(|v| foo += v), Err(4));
+    /// assert_eq!(foo, 0);
+    /// ```
+    fn tap_ref_ok<R, F: FnOnce(&T) -> R>(self, f: F) -> Self;
+
     /// Executes a closure if the value is `Result::Err(E)`.
     /// 
     /// # Examples
@@ -183,6 +214,13 @@ impl<T, E> TapResultOps<T, E> for Result<T, E> {
         self
     }
 
+    fn tap_ref_ok<R, F: FnOnce(&T) -> R>(self, f: F) -> Self {
+        if let Ok(ref val) = self {
+            let _ = f(val);
+        }
+        self
+    }
+
     fn tap_err<R, F: FnOnce(&mut E) -> R>(mut self, f: F) -> Self {
         if let Err(mut val) = self.as_mut() {
             let _ = f(&mut val);
@@ -214,6 +252,31 @@ pub trait TapOptionOps<T> {
     /// ```
     fn tap_some<R, F: FnOnce(&mut T) -> R>(self, f: F) -> Self;
 
+    /// Executes a closure with a read-only reference if the value is `Option::Some(T)`.
+    ///
+    /// Unlike [`tap_some`](TapOptionOps::tap_some), the closure only receives `&T`, so it
+    /// cannot accidentally mutate the contained value; useful when the side effect only
+    /// needs to read, clone or send a copy of the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let mut foo = 0;
+    /// let res: Option<u32> = Some(4);
+    /// assert_eq!(res.tap_ref_some(|v| foo += v), Some(4));
+    /// assert_eq!(foo, 4);
+    /// ```
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let mut foo = 0;
+    /// let res: Option<u32> = None;
+    /// assert_eq!(res.tap_ref_some(|v| foo += v), None);
+    /// assert_eq!(foo, 0);
+    /// ```
+    fn tap_ref_some<R, F: FnOnce(&T) -> R>(self, f: F) -> Self;
+
     /// Executes a closure if the value is `Option::None`.
     /// 
     /// # Examples
@@ -244,6 +307,13 @@ impl<T> TapOptionOps<T> for Option<T> {
         self
     }
 
+    fn tap_ref_some<R, F: FnOnce(&T) -> R>(self, f: F) -> Self {
+        if let Some(ref val) = self {
+            let _ = f(val);
+        }
+        self
+    }
+
     fn tap_none<R, F: FnOnce() -> R>(self, f: F) -> Self {
         if self.is_none() {
             let _ = f();
@@ -267,6 +337,36 @@ pub trait TapOps: Sized {
     /// ```
     fn tap<R, F>(self, f: F) -> Self
         where F: FnOnce(&mut Self) -> R;
+
+    /// Executes a closure on an object if a predicate over the object holds, discarding the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let mut foo = 0;
+    /// let data = 4.tap_if(|v| *v > 0, |v| foo += *v);
+    /// assert_eq!(data, 4);
+    /// assert_eq!(foo, 4);
+    /// ```
+    fn tap_if<R, F, P>(self, pred: P, f: F) -> Self
+        where F: FnOnce(&mut Self) -> R,
+              P: FnOnce(&Self) -> bool;
+
+    /// Executes a closure on an object unless a predicate over the object holds, discarding the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let mut foo = 0;
+    /// let data = 4.tap_unless(|v| *v > 0, |v| foo += *v);
+    /// assert_eq!(data, 4);
+    /// assert_eq!(foo, 0);
+    /// ```
+    fn tap_unless<R, F, P>(self, pred: P, f: F) -> Self
+        where F: FnOnce(&mut Self) -> R,
+              P: FnOnce(&Self) -> bool;
 }
 
 impl<T> TapOps for T where T: Sized {
@@ -276,4 +376,74 @@ impl<T> TapOps for T where T: Sized {
         let _ = f(&mut self);
         self
     }
-}
\ No newline at end of file
+
+    fn tap_if<R, F, P>(mut self, pred: P, f: F) -> Self
+        where F: FnOnce(&mut Self) -> R,
+              P: FnOnce(&Self) -> bool
+    {
+        if pred(&self) {
+            let _ = f(&mut self);
+        }
+        self
+    }
+
+    fn tap_unless<R, F, P>(mut self, pred: P, f: F) -> Self
+        where F: FnOnce(&mut Self) -> R,
+              P: FnOnce(&Self) -> bool
+    {
+        if !pred(&self) {
+            let _ = f(&mut self);
+        }
+        self
+    }
+}
+
+/// Pipe operations for all types.
+///
+/// Unlike [`TapOps::tap`], which always hands back `Self`, `Pipe` moves the
+/// value *through* a closure that is free to return a different type. This
+/// allows left-to-right chains such as `get_numbers().pipe(|v| v.len())`
+/// instead of nesting calls.
+pub trait Pipe: Sized {
+    /// Passes `self` by value into `f`, returning whatever `f` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let length = vec![1, 2, 3].pipe(|v| v.len());
+    /// assert_eq!(length, 3);
+    /// ```
+    fn pipe<R, F: FnOnce(Self) -> R>(self, f: F) -> R {
+        f(self)
+    }
+
+    /// Passes `&self` into `f`, returning whatever `f` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let length = vec![1, 2, 3].pipe_ref(|v| v.len());
+    /// assert_eq!(length, 3);
+    /// ```
+    fn pipe_ref<'a, R, F: FnOnce(&'a Self) -> R>(&'a self, f: F) -> R {
+        f(self)
+    }
+
+    /// Passes `&mut self` into `f`, returning whatever `f` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tap::*;
+    /// let mut values = vec![1, 2, 3];
+    /// let popped = values.pipe_mut(|v| v.pop());
+    /// assert_eq!(popped, Some(3));
+    /// ```
+    fn pipe_mut<'a, R, F: FnOnce(&'a mut Self) -> R>(&'a mut self, f: F) -> R {
+        f(self)
+    }
+}
+
+impl<T> Pipe for T where T: Sized {}
\ No newline at end of file